@@ -0,0 +1,121 @@
+use crate::database::with_db;
+use crate::models::*;
+use chrono::Utc;
+use rusqlite::types::ToSql;
+use rusqlite::params_from_iter;
+
+/// Build a `WHERE` clause and its bound parameters from whichever filter
+/// fields are `Some`, so the query never interpolates user input directly.
+fn build_filter_clause(filter: &AnalyticsFilter) -> (String, Vec<Box<dyn ToSql>>) {
+    let mut clauses = vec!["team_id = ?".to_string()];
+    let mut params: Vec<Box<dyn ToSql>> = vec![Box::new(filter.team_id.clone())];
+
+    if let Some(member_id) = &filter.member_id {
+        clauses.push("member_id = ?".to_string());
+        params.push(Box::new(member_id.clone()));
+    }
+    if let Some(category_id) = &filter.category_id {
+        clauses.push("category_id = ?".to_string());
+        params.push(Box::new(category_id.clone()));
+    }
+    if let Some(status) = &filter.status {
+        clauses.push("status = ?".to_string());
+        params.push(Box::new(status.clone()));
+    }
+    if let Some(start) = &filter.start {
+        clauses.push("end_date >= ?".to_string());
+        params.push(Box::new(start.clone()));
+    }
+    if let Some(end) = &filter.end {
+        clauses.push("start_date <= ?".to_string());
+        params.push(Box::new(end.clone()));
+    }
+
+    (clauses.join(" AND "), params)
+}
+
+/// Compute dashboard-ready task aggregates for a team, scoped by `filter`.
+pub fn get_analytics(filter: AnalyticsFilter) -> Result<Analytics, String> {
+    let today = Utc::now().format("%Y-%m-%d").to_string();
+
+    with_db(|conn| {
+        let (where_clause, params) = build_filter_clause(&filter);
+        let total: i64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM tasks WHERE {}", where_clause),
+            params_from_iter(params.iter()),
+            |row| row.get(0),
+        )?;
+
+        let (where_clause, params) = build_filter_clause(&filter);
+        let completed: i64 = conn.query_row(
+            &format!(
+                "SELECT COUNT(*) FROM tasks WHERE {} AND status = 'completed'",
+                where_clause
+            ),
+            params_from_iter(params.iter()),
+            |row| row.get(0),
+        )?;
+
+        let (where_clause, mut params) = build_filter_clause(&filter);
+        params.push(Box::new(today.clone()));
+        let overdue: i64 = conn.query_row(
+            &format!(
+                "SELECT COUNT(*) FROM tasks WHERE {} AND end_date < ? AND status != 'completed'",
+                where_clause
+            ),
+            params_from_iter(params.iter()),
+            |row| row.get(0),
+        )?;
+
+        let (where_clause, params) = build_filter_clause(&filter);
+        let mut stmt = conn.prepare(&format!(
+            "SELECT status, COUNT(*) FROM tasks WHERE {} GROUP BY status",
+            where_clause
+        ))?;
+        let by_status = stmt
+            .query_map(params_from_iter(params.iter()), |row| {
+                Ok(StatusCount {
+                    status: row.get(0)?,
+                    count: row.get(1)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        let (where_clause, params) = build_filter_clause(&filter);
+        let mut stmt = conn.prepare(&format!(
+            "SELECT member_id, COUNT(*) FROM tasks WHERE {} GROUP BY member_id",
+            where_clause
+        ))?;
+        let by_member = stmt
+            .query_map(params_from_iter(params.iter()), |row| {
+                Ok(MemberCount {
+                    member_id: row.get(0)?,
+                    count: row.get(1)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        let (where_clause, params) = build_filter_clause(&filter);
+        let mut stmt = conn.prepare(&format!(
+            "SELECT category_id, COUNT(*) FROM tasks WHERE {} GROUP BY category_id",
+            where_clause
+        ))?;
+        let by_category = stmt
+            .query_map(params_from_iter(params.iter()), |row| {
+                Ok(CategoryCount {
+                    category_id: row.get(0)?,
+                    count: row.get(1)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        Ok(Analytics {
+            total,
+            completed,
+            overdue,
+            by_status,
+            by_member,
+            by_category,
+        })
+    })
+}
@@ -2,23 +2,118 @@ use crate::models::*;
 use chrono::Utc;
 use log::info;
 use once_cell::sync::Lazy;
-use rusqlite::{params, Connection, Result};
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{params, Connection, OptionalExtension, Result};
+use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::Mutex;
 use uuid::Uuid;
 
-static DB: Lazy<Mutex<Connection>> = Lazy::new(|| {
-    info!("Initializing database...");
-    let conn = init_db().expect("Failed to initialize database");
-    info!("Database connection established");
-    Mutex::new(conn)
+type DbPool = Pool<SqliteConnectionManager>;
+
+/// A single ordered schema change applied to databases created by an older
+/// version of the app. The base schema created by `init_schema` always reflects
+/// the latest structure, so fresh installs never run these; only upgrades do.
+struct Migration {
+    version: i32,
+    up: &'static str,
+}
+
+/// Added in schema version 1. A fresh install gets this table by having
+/// `init_schema` run this DDL directly (not as a migration); the version-1
+/// entry in `MIGRATIONS` is what brings a pre-existing (pre-reminders)
+/// database up to date.
+const REMINDERS_DDL: &str = "
+    CREATE TABLE IF NOT EXISTS reminders (
+        id TEXT PRIMARY KEY,
+        task_id TEXT NOT NULL,
+        offset_before_end TEXT NOT NULL,
+        message TEXT NOT NULL DEFAULT '',
+        fired_at TEXT,
+        FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_reminders_task ON reminders(task_id);
+";
+
+/// Ordered by `version`. Add new entries here (e.g. `ALTER TABLE ...`) instead
+/// of editing the `CREATE TABLE` block below, which must stay in sync so that
+/// a fresh install lands on `latest_version()` without replaying history.
+const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    up: REMINDERS_DDL,
+}];
+
+fn latest_version() -> i32 {
+    MIGRATIONS.last().map(|m| m.version).unwrap_or(0)
+}
+
+fn table_exists(conn: &Connection, name: &str) -> Result<bool> {
+    conn.query_row(
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1",
+        params![name],
+        |_| Ok(()),
+    )
+    .optional()
+    .map(|row| row.is_some())
+}
+
+fn current_schema_version(conn: &Connection) -> Result<i32> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL);",
+    )?;
+    let version: Option<i32> = conn
+        .query_row("SELECT version FROM schema_version LIMIT 1", [], |row| {
+            row.get(0)
+        })
+        .optional()?;
+    Ok(version.unwrap_or(0))
+}
+
+fn stamp_schema_version(conn: &Connection, version: i32) -> Result<()> {
+    conn.execute("DELETE FROM schema_version", [])?;
+    conn.execute(
+        "INSERT INTO schema_version (version) VALUES (?1)",
+        params![version],
+    )?;
+    Ok(())
+}
+
+/// Apply every migration newer than the stored version, one transaction per
+/// step so a failure rolls back that step without re-running earlier ones.
+fn run_migrations(conn: &mut Connection) -> Result<()> {
+    let current = current_schema_version(conn)?;
+    let mut version = current;
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        info!("Applying schema migration {}", migration.version);
+        let tx = conn.transaction()?;
+        tx.execute_batch(migration.up)?;
+        tx.execute("DELETE FROM schema_version", [])?;
+        tx.execute(
+            "INSERT INTO schema_version (version) VALUES (?1)",
+            params![migration.version],
+        )?;
+        tx.commit()?;
+        version = migration.version;
+    }
+    // `current_schema_version` creates the table but not a row, so a database
+    // that needed no pending migrations would otherwise be left with an empty
+    // `schema_version` table; stamp it even when the loop above never ran.
+    stamp_schema_version(conn, version)?;
+    Ok(())
+}
+
+static DB_POOL: Lazy<DbPool> = Lazy::new(|| {
+    info!("Initializing database pool...");
+    let pool = init_pool().expect("Failed to initialize database pool");
+    info!("Database pool established");
+    pool
 });
 
 /// Ensure database is initialized (triggers lazy initialization)
 pub fn init_db_public() -> std::result::Result<(), String> {
     info!("Ensuring database is initialized...");
-    // Access DB to trigger lazy initialization
-    let _guard = DB.lock().map_err(|e| format!("Failed to lock DB: {}", e))?;
+    // Access the pool to trigger lazy initialization
+    DB_POOL.get().map_err(|e| format!("Failed to get DB connection: {}", e))?;
     info!("Database initialized successfully");
     Ok(())
 }
@@ -30,12 +125,32 @@ fn get_db_path() -> PathBuf {
     path.push("scheduler.db");
     path
 }
-fn init_db() -> Result<Connection> {
+
+/// Build the connection pool and bring its schema up to date.
+///
+/// Every pooled connection gets WAL mode and `PRAGMA foreign_keys = ON` set
+/// at creation time, since SQLite enforces foreign keys per-connection and
+/// the schema already declares `ON DELETE CASCADE` / `SET NULL` relations.
+fn init_pool() -> std::result::Result<DbPool, Box<dyn std::error::Error>> {
     let path = get_db_path();
-    let conn = Connection::open(path)?;
+    let manager = SqliteConnectionManager::file(path).with_init(|conn| {
+        conn.execute_batch("PRAGMA journal_mode = WAL; PRAGMA foreign_keys = ON;")
+    });
+    let pool = Pool::new(manager)?;
+
+    let mut conn = pool.get()?;
+    init_schema(&mut conn)?;
+
+    Ok(pool)
+}
+
+fn init_schema(conn: &mut Connection) -> Result<()> {
+    let is_fresh = !table_exists(conn, "teams")?;
 
     conn.execute_batch(
         "
+        CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL);
+
         CREATE TABLE IF NOT EXISTS teams (
             id TEXT PRIMARY KEY,
             name TEXT NOT NULL,
@@ -79,22 +194,56 @@ fn init_db() -> Result<Connection> {
             FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE SET NULL
         );
 
+        CREATE TABLE IF NOT EXISTS task_status_history (
+            id TEXT PRIMARY KEY,
+            task_id TEXT NOT NULL,
+            from_status TEXT NOT NULL,
+            to_status TEXT NOT NULL,
+            changed_at TEXT NOT NULL,
+            FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+        );
+
         CREATE INDEX IF NOT EXISTS idx_tasks_team ON tasks(team_id);
         CREATE INDEX IF NOT EXISTS idx_tasks_member ON tasks(member_id);
         CREATE INDEX IF NOT EXISTS idx_tasks_category ON tasks(category_id);
         CREATE INDEX IF NOT EXISTS idx_members_team ON members(team_id);
         CREATE INDEX IF NOT EXISTS idx_categories_team ON categories(team_id);
+        CREATE TABLE IF NOT EXISTS task_dependencies (
+            predecessor_id TEXT NOT NULL,
+            successor_id TEXT NOT NULL,
+            PRIMARY KEY (predecessor_id, successor_id),
+            FOREIGN KEY (predecessor_id) REFERENCES tasks(id) ON DELETE CASCADE,
+            FOREIGN KEY (successor_id) REFERENCES tasks(id) ON DELETE CASCADE
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_task_status_history_task ON task_status_history(task_id);
+        CREATE INDEX IF NOT EXISTS idx_task_dependencies_successor ON task_dependencies(successor_id);
         ",
     )?;
 
-    Ok(conn)
+    if is_fresh {
+        // A fresh install gets the latest structure directly, including
+        // tables that pre-existing databases only gain via `MIGRATIONS`.
+        conn.execute_batch(REMINDERS_DDL)?;
+        stamp_schema_version(conn, latest_version())?;
+        info!("Fresh database created at schema version {}", latest_version());
+    } else {
+        run_migrations(conn)?;
+    }
+
+    Ok(())
+}
+
+/// Return the database's current schema version.
+pub fn db_version() -> Result<i32, String> {
+    with_db(|conn| conn.query_row("SELECT version FROM schema_version LIMIT 1", [], |row| row.get(0)))
 }
 
 pub fn with_db<F, T>(f: F) -> Result<T, String>
 where
     F: FnOnce(&Connection) -> Result<T>,
 {
-    let conn = DB.lock().map_err(|e| e.to_string())?;
+    let conn = DB_POOL.get().map_err(|e| e.to_string())?;
     f(&conn).map_err(|e| e.to_string())
 }
 
@@ -268,6 +417,7 @@ pub fn delete_category(id: &str) -> Result<(), String> {
 
 // Task operations
 pub fn create_task(data: CreateTask) -> Result<Task, String> {
+    TaskStatus::parse(&data.status)?;
     with_db(|conn| {
         let id = Uuid::new_v4().to_string();
         let now = Utc::now().to_rfc3339();
@@ -319,24 +469,206 @@ pub fn get_tasks(team_id: &str) -> Result<Vec<Task>, String> {
     })
 }
 
+/// Reads the current status, validates the transition, and writes the update
+/// plus its history row all inside one transaction (gated by `AND status =
+/// ?`) so two concurrent updates on the same task can't both read the same
+/// stale status, both pass validation, and corrupt the history trail.
 pub fn update_task(data: UpdateTask) -> Result<(), String> {
+    let mut conn = DB_POOL.get().map_err(|e| e.to_string())?;
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+
+    let current_status: String = tx
+        .query_row(
+            "SELECT status FROM tasks WHERE id = ?1",
+            params![data.id],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+
+    let from = TaskStatus::parse(&current_status)?;
+    let to = TaskStatus::parse(&data.status)?;
+    if from != to && !from.can_transition_to(to) {
+        return Err(format!(
+            "Cannot transition task from '{}' to '{}'",
+            from.as_str(),
+            to.as_str()
+        ));
+    }
+
+    let now = Utc::now().to_rfc3339();
+    let rows_changed = tx
+        .execute(
+            "UPDATE tasks SET member_id = ?1, category_id = ?2, title = ?3, description = ?4, start_date = ?5, end_date = ?6, status = ?7, updated_at = ?8 WHERE id = ?9 AND status = ?10",
+            params![data.member_id, data.category_id, data.title, data.description, data.start_date, data.end_date, data.status, now, data.id, current_status],
+        )
+        .map_err(|e| e.to_string())?;
+
+    if rows_changed == 0 {
+        return Err(format!(
+            "Task status changed concurrently (expected '{}'); refetch and retry",
+            current_status
+        ));
+    }
+
+    if from != to {
+        tx.execute(
+            "INSERT INTO task_status_history (id, task_id, from_status, to_status, changed_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![Uuid::new_v4().to_string(), data.id, from.as_str(), to.as_str(), now],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+
+    tx.commit().map_err(|e| e.to_string())
+}
+
+pub fn delete_task(id: &str) -> Result<(), String> {
+    with_db(|conn| {
+        conn.execute("DELETE FROM tasks WHERE id = ?1", params![id])?;
+        Ok(())
+    })
+}
+
+pub fn get_task_history(task_id: &str) -> Result<Vec<TaskStatusHistory>, String> {
+    with_db(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, from_status, to_status, changed_at FROM task_status_history WHERE task_id = ?1 ORDER BY changed_at",
+        )?;
+        let history = stmt
+            .query_map(params![task_id], |row| {
+                Ok(TaskStatusHistory {
+                    id: row.get(0)?,
+                    task_id: row.get(1)?,
+                    from_status: row.get(2)?,
+                    to_status: row.get(3)?,
+                    changed_at: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>>>()?;
+        Ok(history)
+    })
+}
+
+// Task dependency operations
+/// Both tasks must belong to the same team — a cross-team edge would let a
+/// foreign task id leak into `compute_critical_path`'s per-team graph and
+/// throw off its in-degree bookkeeping.
+pub fn add_dependency(predecessor_id: &str, successor_id: &str) -> Result<(), String> {
+    let conn = DB_POOL.get().map_err(|e| e.to_string())?;
+
+    let predecessor_team: String = conn
+        .query_row(
+            "SELECT team_id FROM tasks WHERE id = ?1",
+            params![predecessor_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+    let successor_team: String = conn
+        .query_row(
+            "SELECT team_id FROM tasks WHERE id = ?1",
+            params![successor_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+
+    if predecessor_team != successor_team {
+        return Err(format!(
+            "Cannot add a dependency across teams (predecessor is on team '{}', successor on '{}')",
+            predecessor_team, successor_team
+        ));
+    }
+
+    conn.execute(
+        "INSERT OR IGNORE INTO task_dependencies (predecessor_id, successor_id) VALUES (?1, ?2)",
+        params![predecessor_id, successor_id],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub fn remove_dependency(predecessor_id: &str, successor_id: &str) -> Result<(), String> {
     with_db(|conn| {
-        let now = Utc::now().to_rfc3339();
         conn.execute(
-            "UPDATE tasks SET member_id = ?1, category_id = ?2, title = ?3, description = ?4, start_date = ?5, end_date = ?6, status = ?7, updated_at = ?8 WHERE id = ?9",
-            params![data.member_id, data.category_id, data.title, data.description, data.start_date, data.end_date, data.status, now, data.id],
+            "DELETE FROM task_dependencies WHERE predecessor_id = ?1 AND successor_id = ?2",
+            params![predecessor_id, successor_id],
         )?;
         Ok(())
     })
 }
 
-pub fn delete_task(id: &str) -> Result<(), String> {
+pub fn get_dependencies(team_id: &str) -> Result<Vec<TaskDependency>, String> {
     with_db(|conn| {
-        conn.execute("DELETE FROM tasks WHERE id = ?1", params![id])?;
+        let mut stmt = conn.prepare(
+            "SELECT d.predecessor_id, d.successor_id FROM task_dependencies d
+             JOIN tasks t ON t.id = d.predecessor_id
+             WHERE t.team_id = ?1",
+        )?;
+        let dependencies = stmt
+            .query_map(params![team_id], |row| {
+                Ok(TaskDependency {
+                    predecessor_id: row.get(0)?,
+                    successor_id: row.get(1)?,
+                })
+            })?
+            .collect::<Result<Vec<_>>>()?;
+        Ok(dependencies)
+    })
+}
+
+// Reminder operations
+pub fn create_reminder(data: CreateReminder) -> Result<Reminder, String> {
+    humantime::parse_duration(&data.offset_before_end)
+        .map_err(|e| format!("Invalid offset '{}': {}", data.offset_before_end, e))?;
+
+    with_db(|conn| {
+        let id = Uuid::new_v4().to_string();
+        conn.execute(
+            "INSERT INTO reminders (id, task_id, offset_before_end, message, fired_at) VALUES (?1, ?2, ?3, ?4, NULL)",
+            params![id, data.task_id, data.offset_before_end, data.message],
+        )?;
+        Ok(Reminder {
+            id,
+            task_id: data.task_id,
+            offset_before_end: data.offset_before_end,
+            message: data.message,
+            fired_at: None,
+        })
+    })
+}
+
+pub fn dismiss_reminder(id: &str) -> Result<(), String> {
+    with_db(|conn| {
+        let now = Utc::now().to_rfc3339();
+        conn.execute(
+            "UPDATE reminders SET fired_at = ?1 WHERE id = ?2",
+            params![now, id],
+        )?;
         Ok(())
     })
 }
 
+pub fn get_reminders(team_id: &str) -> Result<Vec<Reminder>, String> {
+    with_db(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT r.id, r.task_id, r.offset_before_end, r.message, r.fired_at
+             FROM reminders r
+             JOIN tasks t ON t.id = r.task_id
+             WHERE t.team_id = ?1",
+        )?;
+        let reminders = stmt
+            .query_map(params![team_id], |row| {
+                Ok(Reminder {
+                    id: row.get(0)?,
+                    task_id: row.get(1)?,
+                    offset_before_end: row.get(2)?,
+                    message: row.get(3)?,
+                    fired_at: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>>>()?;
+        Ok(reminders)
+    })
+}
+
 // Initialize default categories for a team
 pub fn init_default_categories(team_id: &str) -> Result<Vec<Category>, String> {
     let defaults = vec![
@@ -382,79 +714,268 @@ pub fn export_data(team_id: &str) -> Result<String, String> {
     let members = get_members(team_id)?;
     let categories = get_categories(team_id)?;
     let tasks = get_tasks(team_id)?;
+    let task_dependencies = get_dependencies(team_id)?;
+    let reminders = get_reminders(team_id)?;
 
     let export = serde_json::json!({
         "team": team,
         "members": members,
         "categories": categories,
         "tasks": tasks,
+        "task_dependencies": task_dependencies,
+        "reminders": reminders,
         "exported_at": Utc::now().to_rfc3339()
     });
 
     serde_json::to_string_pretty(&export).map_err(|e| e.to_string())
 }
 
-pub fn import_data(team_id: &str, json_data: &str) -> Result<(), String> {
+fn tx_members(tx: &rusqlite::Transaction, team_id: &str) -> Result<Vec<Member>> {
+    let mut stmt = tx.prepare(
+        "SELECT id, team_id, name, role, color, created_at FROM members WHERE team_id = ?1 ORDER BY created_at",
+    )?;
+    stmt.query_map(params![team_id], |row| {
+        Ok(Member {
+            id: row.get(0)?,
+            team_id: row.get(1)?,
+            name: row.get(2)?,
+            role: row.get(3)?,
+            color: row.get(4)?,
+            created_at: row.get(5)?,
+        })
+    })?
+    .collect::<Result<Vec<_>>>()
+}
+
+fn tx_categories(tx: &rusqlite::Transaction, team_id: &str) -> Result<Vec<Category>> {
+    let mut stmt = tx.prepare(
+        "SELECT id, team_id, name, color, order_index, created_at FROM categories WHERE team_id = ?1 ORDER BY order_index",
+    )?;
+    stmt.query_map(params![team_id], |row| {
+        Ok(Category {
+            id: row.get(0)?,
+            team_id: row.get(1)?,
+            name: row.get(2)?,
+            color: row.get(3)?,
+            order_index: row.get(4)?,
+            created_at: row.get(5)?,
+        })
+    })?
+    .collect::<Result<Vec<_>>>()
+}
+
+/// `mode` is `"replace"` (wipe the team's data first, like the old behavior)
+/// or `"merge"` (keep existing rows, matching members/categories by name so
+/// re-importing the same export doesn't duplicate *them*; tasks, dependencies
+/// and reminders have no such identity and are always inserted fresh). Either
+/// way, each imported task's `member_id`/`category_id` is rewritten through
+/// the id map built while importing members/categories, and each dependency's
+/// or reminder's task reference is rewritten through the id map built while
+/// importing tasks, so assignments survive the round-trip instead of being
+/// dropped.
+///
+/// The delete-then-reinsert sequence runs inside a single transaction, so a
+/// failure partway through rolls back to the pre-import state instead of
+/// leaving the team with its old data deleted and only a partial reimport.
+pub fn import_data(team_id: &str, json_data: &str, mode: &str) -> Result<ImportSummary, String> {
+    if mode != "replace" && mode != "merge" {
+        return Err(format!("Unknown import mode: '{}' (expected 'replace' or 'merge')", mode));
+    }
     let data: serde_json::Value = serde_json::from_str(json_data).map_err(|e| e.to_string())?;
 
-    // Delete existing data for this team before importing
-    info!("Deleting existing data for team: {}", team_id);
-    with_db(|conn| {
-        conn.execute("DELETE FROM tasks WHERE team_id = ?1", params![team_id])?;
-        conn.execute("DELETE FROM categories WHERE team_id = ?1", params![team_id])?;
-        conn.execute("DELETE FROM members WHERE team_id = ?1", params![team_id])?;
-        Ok(())
-    })?;
-    info!("Existing data deleted, importing new data...");
+    let mut conn = DB_POOL.get().map_err(|e| e.to_string())?;
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+
+    if mode == "replace" {
+        info!("Replacing existing data for team: {}", team_id);
+        tx.execute("DELETE FROM tasks WHERE team_id = ?1", params![team_id])
+            .map_err(|e| e.to_string())?;
+        tx.execute("DELETE FROM categories WHERE team_id = ?1", params![team_id])
+            .map_err(|e| e.to_string())?;
+        tx.execute("DELETE FROM members WHERE team_id = ?1", params![team_id])
+            .map_err(|e| e.to_string())?;
+    }
+
+    let existing_members = tx_members(&tx, team_id).map_err(|e| e.to_string())?;
+    let existing_categories = tx_categories(&tx, team_id).map_err(|e| e.to_string())?;
+
+    let mut summary = ImportSummary::default();
+    let mut member_id_map: HashMap<String, String> = HashMap::new();
+    let mut category_id_map: HashMap<String, String> = HashMap::new();
+    let mut task_id_map: HashMap<String, String> = HashMap::new();
 
-    // Import members
     if let Some(members) = data.get("members").and_then(|v| v.as_array()) {
         for member in members {
+            let export_id = member.get("id").and_then(|v| v.as_str()).unwrap_or("");
             let name = member.get("name").and_then(|v| v.as_str()).unwrap_or("");
-            let role = member.get("role").and_then(|v| v.as_str()).unwrap_or("");
+            let role = member.get("role").and_then(|v| v.as_str()).unwrap_or("member");
             let color = member.get("color").and_then(|v| v.as_str()).unwrap_or("#4A90D9");
-            create_member(CreateMember {
-                team_id: team_id.to_string(),
-                name: name.to_string(),
-                role: role.to_string(),
-                color: color.to_string(),
-            })?;
+
+            if mode == "merge" {
+                if let Some(matched) = existing_members.iter().find(|m| m.name == name) {
+                    let matched_id = matched.id.clone();
+                    tx.execute(
+                        "UPDATE members SET name = ?1, role = ?2, color = ?3 WHERE id = ?4",
+                        params![name, role, color, matched_id],
+                    )
+                    .map_err(|e| e.to_string())?;
+                    member_id_map.insert(export_id.to_string(), matched_id);
+                    summary.updated += 1;
+                    continue;
+                }
+            }
+
+            let new_id = Uuid::new_v4().to_string();
+            let now = Utc::now().to_rfc3339();
+            tx.execute(
+                "INSERT INTO members (id, team_id, name, role, color, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![new_id, team_id, name, role, color, now],
+            )
+            .map_err(|e| e.to_string())?;
+            member_id_map.insert(export_id.to_string(), new_id);
+            summary.inserted += 1;
         }
     }
-    
-    // Import categories
+
     if let Some(categories) = data.get("categories").and_then(|v| v.as_array()) {
         for cat in categories {
+            let export_id = cat.get("id").and_then(|v| v.as_str()).unwrap_or("");
             let name = cat.get("name").and_then(|v| v.as_str()).unwrap_or("");
             let color = cat.get("color").and_then(|v| v.as_str()).unwrap_or("#4A90D9");
-            create_category(CreateCategory {
-                team_id: team_id.to_string(),
-                name: name.to_string(),
-                color: color.to_string(),
-            })?;
+
+            if mode == "merge" {
+                if let Some(matched) = existing_categories.iter().find(|c| c.name == name) {
+                    let matched_id = matched.id.clone();
+                    tx.execute(
+                        "UPDATE categories SET name = ?1, color = ?2 WHERE id = ?3",
+                        params![name, color, matched_id],
+                    )
+                    .map_err(|e| e.to_string())?;
+                    category_id_map.insert(export_id.to_string(), matched_id);
+                    summary.updated += 1;
+                    continue;
+                }
+            }
+
+            let new_id = Uuid::new_v4().to_string();
+            let now = Utc::now().to_rfc3339();
+            let order_index: i32 = tx
+                .query_row(
+                    "SELECT COALESCE(MAX(order_index), -1) + 1 FROM categories WHERE team_id = ?1",
+                    params![team_id],
+                    |row| row.get(0),
+                )
+                .map_err(|e| e.to_string())?;
+            tx.execute(
+                "INSERT INTO categories (id, team_id, name, color, order_index, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![new_id, team_id, name, color, order_index, now],
+            )
+            .map_err(|e| e.to_string())?;
+            category_id_map.insert(export_id.to_string(), new_id);
+            summary.inserted += 1;
         }
     }
-    
-    // Import tasks
+
     if let Some(tasks) = data.get("tasks").and_then(|v| v.as_array()) {
         for task in tasks {
+            let export_id = task.get("id").and_then(|v| v.as_str()).unwrap_or("");
+            let exported_member_id = task.get("member_id").and_then(|v| v.as_str());
+            let exported_category_id = task.get("category_id").and_then(|v| v.as_str());
+
+            // A reference that doesn't resolve (e.g. the member was dropped from
+            // the export) leaves the task unassigned rather than losing the task.
+            let member_id = exported_member_id.and_then(|id| match member_id_map.get(id) {
+                Some(mapped) => Some(mapped.clone()),
+                None => {
+                    info!("Importing task unassigned: member '{}' did not resolve", id);
+                    None
+                }
+            });
+            let category_id = exported_category_id.and_then(|id| match category_id_map.get(id) {
+                Some(mapped) => Some(mapped.clone()),
+                None => {
+                    info!("Importing task uncategorized: category '{}' did not resolve", id);
+                    None
+                }
+            });
+
             let title = task.get("title").and_then(|v| v.as_str()).unwrap_or("");
             let description = task.get("description").and_then(|v| v.as_str()).unwrap_or("");
             let start_date = task.get("start_date").and_then(|v| v.as_str()).unwrap_or("");
             let end_date = task.get("end_date").and_then(|v| v.as_str()).unwrap_or("");
             let status = task.get("status").and_then(|v| v.as_str()).unwrap_or("not_started");
-            create_task(CreateTask {
-                team_id: team_id.to_string(),
-                member_id: None,
-                category_id: None,
-                title: title.to_string(),
-                description: description.to_string(),
-                start_date: start_date.to_string(),
-                end_date: end_date.to_string(),
-                status: status.to_string(),
-            })?;
+
+            if let Err(e) = TaskStatus::parse(status) {
+                info!("Skipping task '{}': {}", title, e);
+                summary.skipped += 1;
+                continue;
+            }
+
+            let new_id = Uuid::new_v4().to_string();
+            let now = Utc::now().to_rfc3339();
+            let inserted = tx.execute(
+                "INSERT INTO tasks (id, team_id, member_id, category_id, title, description, start_date, end_date, status, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                params![new_id, team_id, member_id, category_id, title, description, start_date, end_date, status, now, now],
+            );
+            match inserted {
+                Ok(_) => {
+                    task_id_map.insert(export_id.to_string(), new_id);
+                    summary.inserted += 1;
+                }
+                Err(e) => {
+                    info!("Skipping task '{}': {}", title, e);
+                    summary.skipped += 1;
+                }
+            }
         }
     }
-    
-    Ok(())
+
+    if let Some(deps) = data.get("task_dependencies").and_then(|v| v.as_array()) {
+        for dep in deps {
+            let exported_predecessor = dep.get("predecessor_id").and_then(|v| v.as_str()).unwrap_or("");
+            let exported_successor = dep.get("successor_id").and_then(|v| v.as_str()).unwrap_or("");
+
+            match (task_id_map.get(exported_predecessor), task_id_map.get(exported_successor)) {
+                (Some(predecessor_id), Some(successor_id)) => {
+                    tx.execute(
+                        "INSERT OR IGNORE INTO task_dependencies (predecessor_id, successor_id) VALUES (?1, ?2)",
+                        params![predecessor_id, successor_id],
+                    )
+                    .map_err(|e| e.to_string())?;
+                }
+                _ => info!("Skipping dependency: predecessor or successor task did not resolve"),
+            }
+        }
+    }
+
+    if let Some(reminders) = data.get("reminders").and_then(|v| v.as_array()) {
+        for reminder in reminders {
+            let exported_task_id = reminder.get("task_id").and_then(|v| v.as_str()).unwrap_or("");
+            let task_id = match task_id_map.get(exported_task_id) {
+                Some(mapped) => mapped.clone(),
+                None => {
+                    info!("Skipping reminder: task '{}' did not resolve", exported_task_id);
+                    continue;
+                }
+            };
+
+            let offset_before_end = reminder.get("offset_before_end").and_then(|v| v.as_str()).unwrap_or("");
+            if humantime::parse_duration(offset_before_end).is_err() {
+                info!("Skipping reminder with invalid offset '{}'", offset_before_end);
+                continue;
+            }
+            let message = reminder.get("message").and_then(|v| v.as_str()).unwrap_or("");
+            let fired_at = reminder.get("fired_at").and_then(|v| v.as_str());
+
+            tx.execute(
+                "INSERT INTO reminders (id, task_id, offset_before_end, message, fired_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![Uuid::new_v4().to_string(), task_id, offset_before_end, message, fired_at],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+    }
+
+    tx.commit().map_err(|e| e.to_string())?;
+    Ok(summary)
 }
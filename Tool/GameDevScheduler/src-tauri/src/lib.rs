@@ -1,5 +1,8 @@
+mod analytics;
 mod database;
 mod models;
+mod reminders;
+mod scheduling;
 
 use log::{info, error};
 use models::*;
@@ -93,6 +96,11 @@ fn cmd_delete_task(id: String) -> Result<(), String> {
     database::delete_task(&id)
 }
 
+#[tauri::command]
+fn cmd_get_task_history(task_id: String) -> Result<Vec<TaskStatusHistory>, String> {
+    database::get_task_history(&task_id)
+}
+
 // Export command
 #[tauri::command]
 fn cmd_export_data(team_id: String) -> Result<String, String> {
@@ -101,8 +109,57 @@ fn cmd_export_data(team_id: String) -> Result<String, String> {
 
 // Import command
 #[tauri::command]
-fn cmd_import_data(team_id: String, json_data: String) -> Result<(), String> {
-    database::import_data(&team_id, &json_data)
+fn cmd_import_data(team_id: String, json_data: String, mode: String) -> Result<ImportSummary, String> {
+    database::import_data(&team_id, &json_data, &mode)
+}
+
+// Task dependency commands
+#[tauri::command]
+fn cmd_add_dependency(predecessor_id: String, successor_id: String) -> Result<(), String> {
+    database::add_dependency(&predecessor_id, &successor_id)
+}
+
+#[tauri::command]
+fn cmd_remove_dependency(predecessor_id: String, successor_id: String) -> Result<(), String> {
+    database::remove_dependency(&predecessor_id, &successor_id)
+}
+
+#[tauri::command]
+fn cmd_get_dependencies(team_id: String) -> Result<Vec<TaskDependency>, String> {
+    database::get_dependencies(&team_id)
+}
+
+#[tauri::command]
+fn cmd_compute_critical_path(team_id: String) -> Result<CriticalPath, String> {
+    scheduling::compute_critical_path(&team_id)
+}
+
+// Analytics commands
+#[tauri::command]
+fn cmd_get_analytics(filter: AnalyticsFilter) -> Result<Analytics, String> {
+    analytics::get_analytics(filter)
+}
+
+// Reminder commands
+#[tauri::command]
+fn cmd_create_reminder(data: CreateReminder) -> Result<Reminder, String> {
+    database::create_reminder(data)
+}
+
+#[tauri::command]
+fn cmd_get_due_reminders(team_id: String) -> Result<Vec<DueReminder>, String> {
+    reminders::get_due_reminders(&team_id)
+}
+
+#[tauri::command]
+fn cmd_dismiss_reminder(id: String) -> Result<(), String> {
+    database::dismiss_reminder(&id)
+}
+
+// Schema commands
+#[tauri::command]
+fn cmd_db_version() -> Result<i32, String> {
+    database::db_version()
 }
 
 // File I/O commands
@@ -165,8 +222,18 @@ pub fn run() {
             cmd_get_tasks,
             cmd_update_task,
             cmd_delete_task,
+            cmd_get_task_history,
+            cmd_get_analytics,
+            cmd_add_dependency,
+            cmd_remove_dependency,
+            cmd_get_dependencies,
+            cmd_compute_critical_path,
+            cmd_create_reminder,
+            cmd_get_due_reminders,
+            cmd_dismiss_reminder,
             cmd_export_data,
             cmd_import_data,
+            cmd_db_version,
             cmd_write_file,
             cmd_read_file,
         ])
@@ -42,6 +42,161 @@ pub struct Task {
     pub updated_at: String,
 }
 
+/// The allowed values of `Task::status`, plus which moves between them are legal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    NotStarted,
+    InProgress,
+    Completed,
+}
+
+impl TaskStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaskStatus::NotStarted => "not_started",
+            TaskStatus::InProgress => "in_progress",
+            TaskStatus::Completed => "completed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "not_started" => Ok(TaskStatus::NotStarted),
+            "in_progress" => Ok(TaskStatus::InProgress),
+            "completed" => Ok(TaskStatus::Completed),
+            other => Err(format!("Unknown task status: '{}'", other)),
+        }
+    }
+
+    /// Whether moving from `self` to `next` is a legal transition.
+    pub fn can_transition_to(self, next: TaskStatus) -> bool {
+        matches!(
+            (self, next),
+            (TaskStatus::NotStarted, TaskStatus::InProgress)
+                | (TaskStatus::InProgress, TaskStatus::Completed)
+                | (TaskStatus::InProgress, TaskStatus::NotStarted)
+                | (TaskStatus::Completed, TaskStatus::InProgress)
+        )
+    }
+}
+
+/// One recorded move of a task between statuses, used to audit history and
+/// compute time-in-status.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskStatusHistory {
+    pub id: String,
+    pub task_id: String,
+    pub from_status: String,
+    pub to_status: String,
+    pub changed_at: String,
+}
+
+/// Narrows a `cmd_get_analytics` query to a slice of a team's tasks. Every
+/// field besides `team_id` is optional and only contributes a `WHERE` clause
+/// when set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsFilter {
+    pub team_id: String,
+    pub member_id: Option<String>,
+    pub category_id: Option<String>,
+    pub status: Option<String>,
+    pub start: Option<String>,
+    pub end: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusCount {
+    pub status: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberCount {
+    pub member_id: Option<String>,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryCount {
+    pub category_id: Option<String>,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Analytics {
+    pub total: i64,
+    pub completed: i64,
+    pub overdue: i64,
+    pub by_status: Vec<StatusCount>,
+    pub by_member: Vec<MemberCount>,
+    pub by_category: Vec<CategoryCount>,
+}
+
+/// A "predecessor must finish before successor can start" edge between two tasks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDependency {
+    pub predecessor_id: String,
+    pub successor_id: String,
+}
+
+/// A task's computed schedule within its team's critical-path analysis.
+/// Times are in days from the project's earliest start.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskSchedule {
+    pub task_id: String,
+    pub earliest_start: f64,
+    pub earliest_finish: f64,
+    pub latest_start: f64,
+    pub latest_finish: f64,
+    pub slack: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriticalPath {
+    pub schedules: Vec<TaskSchedule>,
+    /// Task ids with zero slack, in schedule order.
+    pub critical_path: Vec<String>,
+}
+
+/// A deadline reminder for a task, firing `offset_before_end` (e.g. "1d",
+/// "2h30m", parsed with `humantime`) before the task's `end_date`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reminder {
+    pub id: String,
+    pub task_id: String,
+    pub offset_before_end: String,
+    pub message: String,
+    pub fired_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateReminder {
+    pub task_id: String,
+    pub offset_before_end: String,
+    pub message: String,
+}
+
+/// A reminder whose fire time has passed, with enough task/member context
+/// for the frontend to show a native notification without another lookup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DueReminder {
+    pub id: String,
+    pub task_id: String,
+    pub task_title: String,
+    pub member_name: Option<String>,
+    pub member_color: Option<String>,
+    pub message: String,
+}
+
+/// Row counts from a `cmd_import_data` run, across members, categories, and tasks.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportSummary {
+    pub inserted: usize,
+    pub updated: usize,
+    pub skipped: usize,
+}
+
 // DTOs for creation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTeam {
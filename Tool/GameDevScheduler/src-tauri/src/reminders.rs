@@ -0,0 +1,84 @@
+use crate::database::with_db;
+use crate::models::*;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use log::warn;
+use rusqlite::params;
+
+fn parse_end_date(s: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap()));
+    }
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|_| format!("Invalid date: '{}'", s))
+}
+
+/// Reminders whose fire time (`task.end_date - offset_before_end`) has
+/// already passed and that haven't been dismissed, for every task on the team.
+pub fn get_due_reminders(team_id: &str) -> Result<Vec<DueReminder>, String> {
+    let rows = with_db(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT r.id, r.task_id, r.offset_before_end, r.message, t.end_date, t.title, m.name, m.color
+             FROM reminders r
+             JOIN tasks t ON t.id = r.task_id
+             LEFT JOIN members m ON m.id = t.member_id
+             WHERE t.team_id = ?1 AND r.fired_at IS NULL",
+        )?;
+        stmt.query_map(params![team_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, String>(5)?,
+                row.get::<_, Option<String>>(6)?,
+                row.get::<_, Option<String>>(7)?,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()
+    })?;
+
+    let now = Utc::now();
+    let mut due = Vec::new();
+    for (id, task_id, offset_before_end, message, end_date, task_title, member_name, member_color) in rows
+    {
+        // A malformed date/offset on one reminder shouldn't hide every other
+        // due reminder on the team; skip just the bad row.
+        let end = match parse_end_date(&end_date) {
+            Ok(end) => end,
+            Err(e) => {
+                warn!("Skipping reminder {} for task {}: {}", id, task_id, e);
+                continue;
+            }
+        };
+        let offset = match humantime::parse_duration(&offset_before_end) {
+            Ok(offset) => offset,
+            Err(e) => {
+                warn!(
+                    "Skipping reminder {} for task {}: invalid offset '{}': {}",
+                    id, task_id, offset_before_end, e
+                );
+                continue;
+            }
+        };
+        let fire_at = match chrono::Duration::from_std(offset) {
+            Ok(offset) => end - offset,
+            Err(e) => {
+                warn!("Skipping reminder {} for task {}: {}", id, task_id, e);
+                continue;
+            }
+        };
+        if fire_at <= now {
+            due.push(DueReminder {
+                id,
+                task_id,
+                task_title,
+                member_name,
+                member_color,
+                message,
+            });
+        }
+    }
+    Ok(due)
+}
@@ -0,0 +1,129 @@
+use crate::database::{get_dependencies, get_tasks};
+use crate::models::*;
+use chrono::NaiveDate;
+use std::collections::{HashMap, VecDeque};
+
+fn parse_date(s: &str) -> Result<NaiveDate, String> {
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(date);
+    }
+    chrono::DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.date_naive())
+        .map_err(|_| format!("Invalid date: '{}'", s))
+}
+
+/// Compute each task's earliest/latest start and finish via forward and
+/// backward passes over the dependency graph, and return the zero-slack
+/// critical path. Tasks are nodes; `task_dependencies` rows are edges.
+pub fn compute_critical_path(team_id: &str) -> Result<CriticalPath, String> {
+    let tasks = get_tasks(team_id)?;
+    let dependencies = get_dependencies(team_id)?;
+
+    let mut duration: HashMap<String, f64> = HashMap::new();
+    for task in &tasks {
+        let start = parse_date(&task.start_date)?;
+        let end = parse_date(&task.end_date)?;
+        duration.insert(task.id.clone(), (end - start).num_days() as f64);
+    }
+
+    let mut predecessors: HashMap<String, Vec<String>> = HashMap::new();
+    let mut successors: HashMap<String, Vec<String>> = HashMap::new();
+    let mut in_degree: HashMap<String, usize> = tasks.iter().map(|t| (t.id.clone(), 0)).collect();
+
+    for dep in &dependencies {
+        successors
+            .entry(dep.predecessor_id.clone())
+            .or_default()
+            .push(dep.successor_id.clone());
+        predecessors
+            .entry(dep.successor_id.clone())
+            .or_default()
+            .push(dep.predecessor_id.clone());
+        *in_degree.entry(dep.successor_id.clone()).or_insert(0) += 1;
+    }
+
+    // Kahn's algorithm: repeatedly pop nodes with in-degree zero.
+    let mut remaining = in_degree.clone();
+    let mut queue: VecDeque<String> = remaining
+        .iter()
+        .filter(|(_, &degree)| degree == 0)
+        .map(|(id, _)| id.clone())
+        .collect();
+    let mut order = Vec::new();
+
+    while let Some(id) = queue.pop_front() {
+        order.push(id.clone());
+        if let Some(succs) = successors.get(&id) {
+            for succ in succs {
+                let degree = remaining.get_mut(succ).expect("successor was counted in in_degree");
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(succ.clone());
+                }
+            }
+        }
+    }
+
+    if order.len() != tasks.len() {
+        let cyclic: Vec<String> = remaining
+            .iter()
+            .filter(|(_, &degree)| degree > 0)
+            .map(|(id, _)| id.clone())
+            .collect();
+        return Err(format!(
+            "Dependency cycle detected among tasks: {}",
+            cyclic.join(", ")
+        ));
+    }
+
+    // Forward pass: ES = max predecessor EF, EF = ES + duration.
+    let mut es: HashMap<String, f64> = HashMap::new();
+    let mut ef: HashMap<String, f64> = HashMap::new();
+    for id in &order {
+        let start = predecessors
+            .get(id)
+            .map(|preds| preds.iter().map(|p| ef[p]).fold(0.0_f64, f64::max))
+            .unwrap_or(0.0);
+        let finish = start + duration[id];
+        es.insert(id.clone(), start);
+        ef.insert(id.clone(), finish);
+    }
+    let project_end = ef.values().cloned().fold(0.0_f64, f64::max);
+
+    // Backward pass: LF = min successor LS (or project end), LS = LF - duration.
+    let mut ls: HashMap<String, f64> = HashMap::new();
+    let mut lf: HashMap<String, f64> = HashMap::new();
+    for id in order.iter().rev() {
+        let finish = successors
+            .get(id)
+            .map(|succs| succs.iter().map(|s| ls[s]).fold(f64::MAX, f64::min))
+            .unwrap_or(project_end);
+        let start = finish - duration[id];
+        lf.insert(id.clone(), finish);
+        ls.insert(id.clone(), start);
+    }
+
+    const SLACK_EPSILON: f64 = 1e-6;
+    let schedules: Vec<TaskSchedule> = order
+        .iter()
+        .map(|id| TaskSchedule {
+            task_id: id.clone(),
+            earliest_start: es[id],
+            earliest_finish: ef[id],
+            latest_start: ls[id],
+            latest_finish: lf[id],
+            slack: ls[id] - es[id],
+        })
+        .collect();
+
+    let critical_path = schedules
+        .iter()
+        .filter(|s| s.slack.abs() < SLACK_EPSILON)
+        .map(|s| s.task_id.clone())
+        .collect();
+
+    Ok(CriticalPath {
+        schedules,
+        critical_path,
+    })
+}